@@ -34,6 +34,7 @@ pub struct BuildTimingBuilder {
     build_pattern: BuildPattern,
     allow_const: BTreeSet<BuildTimingConst>,
     out_path: Option<String>,
+    manifest_path: Option<String>,
     pub(crate) hook_consts: Vec<Box<dyn BuildConstVal>>,
 }
 
@@ -56,6 +57,7 @@ impl BuildTimingBuilder {
             build_pattern: BuildPattern::default(),
             allow_const: default_allow(),
             out_path: default_out_path,
+            manifest_path: None,
             hook_consts: Vec::new(),
         }
     }
@@ -70,6 +72,20 @@ impl BuildTimingBuilder {
         Ok(out_path)
     }
 
+    /// Sets the build pattern that determines when package rebuilds are triggered.
+    ///
+    /// # Arguments
+    ///
+    /// * `build_pattern` - The `BuildPattern` to use (`Lazy`, `RealTime`, `Custom`, or `Mtime`).
+    ///
+    /// # Returns
+    ///
+    /// A new `BuildTimingBuilder` instance with the specified build pattern.
+    pub fn build_pattern(mut self, build_pattern: BuildPattern) -> Self {
+        self.build_pattern = build_pattern;
+        self
+    }
+
     /// Gets the build pattern.
     ///
     /// # Returns
@@ -115,6 +131,20 @@ impl BuildTimingBuilder {
         self.hook_consts.push(hook);
         self
     }
+
+    /// Additionally writes a JSON manifest of every resolved build constant
+    /// (name, `desc`, value, and `ConstType`) to `path` under `OUT_DIR`,
+    /// so external tooling can ingest build metadata without parsing the
+    /// generated Rust.
+    pub fn emit_manifest<S: Into<String>>(mut self, path: S) -> Self {
+        self.manifest_path = Some(path.into());
+        self
+    }
+
+    /// Gets the manifest path, if [`emit_manifest`](Self::emit_manifest) was set.
+    pub fn get_manifest_path(&self) -> Option<&String> {
+        self.manifest_path.as_ref()
+    }
 }
 
 /// Serialized values for build constants.
@@ -168,12 +198,15 @@ pub enum ConstType {
     Slice,
     /// [`usize`].
     Usize,
+    /// [`&str`](`str`), resolved from `{NAME}` placeholders referencing other
+    /// build constants. See [`BuildTiming`](crate::BuildTiming)'s code generation.
+    Format,
 }
 
 impl Display for ConstType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            ConstType::Str => write!(f, "&str"),
+            ConstType::Str | ConstType::Format => write!(f, "&str"),
             ConstType::Bool => write!(f, "bool"),
             ConstType::Slice => write!(f, "&[u8]"),
             ConstType::Usize => write!(f, "usize"),
@@ -192,6 +225,15 @@ impl Display for ConstType {
 ///   regardless of whether the Rust environment is set to `debug` or `release`.
 /// * `Custom`: The custom build mode, an enhanced version of `RealTime` mode, allowing for user-defined conditions
 ///   to trigger rebuilding a package.
+/// * `Mtime`: The mtime-gated mode. Regeneration of `build_timing.rs` is skipped whenever the
+///   existing generated file is already newer than every tracked input, mirroring how the Rust
+///   bootstrap system only rebuilds native libraries when their sources are newer than the artifact.
+///   This also accounts for each allowed constant's own [`BuildConstVal::rerun_if_changed`] paths
+///   (e.g. the git ref files behind `GIT_SHA`), so those stay in sync too. Constants with no
+///   file-backed input at all (`RUST_VERSION`, `CARGO_PROFILE`, `CARGO_TARGET`, the `BUILD_*`
+///   sysinfo group) have nothing to compare a timestamp against, so `Mtime` mode can still serve a
+///   stale value for them after the first build even though Cargo reran the build script; avoid
+///   combining `Mtime` with those constants if that staleness matters to you.
 ///
 #[derive(Debug, Default, Clone)]
 pub enum BuildPattern {
@@ -206,6 +248,11 @@ pub enum BuildPattern {
         /// See <https://doc.rust-lang.org/cargo/reference/build-scripts.html#rerun-if-env-changed>
         if_env_changed: Vec<String>,
     },
+    Mtime {
+        /// A list of paths whose modification time is compared against the generated
+        /// `build_timing.rs`. If any of them (or the manifest) is newer, the file is regenerated.
+        if_path_changed: Vec<String>,
+    },
 }
 
 impl BuildPattern {
@@ -238,10 +285,44 @@ impl BuildPattern {
                     .iter()
                     .for_each(|p| println!("cargo:rerun-if-changed={p}"));
             }
+            BuildPattern::Mtime { if_path_changed } => {
+                if_path_changed
+                    .iter()
+                    .for_each(|p| println!("cargo:rerun-if-changed={p}"));
+            }
         }
 
         other_keys.for_each(|key| println!("cargo:rerun-if-env-changed={}", key.to_string()));
         println!("cargo:rerun-if-env-changed={DEFINE_SOURCE_DATE_EPOCH}");
         println!("cargo:rerun-if-changed={out_dir}/{DEFINE_BUILD_TIMING_RS}");
     }
+
+    /// For [`BuildPattern::Mtime`], reports whether `dest` (the generated
+    /// `build_timing.rs`) is already newer than every tracked input — `if_path_changed`,
+    /// the manifest, and `extra_inputs` (the per-constant [`BuildConstVal::rerun_if_changed`]
+    /// paths collected by the caller) — meaning regeneration can be skipped. Any other
+    /// pattern, a missing `dest`, or an unreadable timestamp always returns `false`.
+    pub(crate) fn should_skip_regeneration(&self, dest: &str, extra_inputs: &[String]) -> bool {
+        let BuildPattern::Mtime { if_path_changed } = self else {
+            return false;
+        };
+
+        let Ok(out_mtime) = std::fs::metadata(dest).and_then(|m| m.modified()) else {
+            return false;
+        };
+
+        let manifest = std::env::var("CARGO_MANIFEST_DIR")
+            .ok()
+            .map(|dir| format!("{dir}/Cargo.toml"));
+
+        if_path_changed
+            .iter()
+            .chain(extra_inputs)
+            .chain(manifest.iter())
+            .all(|p| {
+                std::fs::metadata(p)
+                    .and_then(|m| m.modified())
+                    .is_ok_and(|mtime| mtime <= out_mtime)
+            })
+    }
 }
@@ -0,0 +1,333 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+};
+
+use crate::{
+    build::{BuildTimingBuilder, ConstType, ConstVal},
+    env::BuildConstVal,
+    err::{BtResult, BuildTimingError},
+};
+
+/// Name of the generated source file written into `OUT_DIR`.
+pub(crate) const DEFINE_BUILD_TIMING_RS: &str = "build_timing.rs";
+
+/// The outcome of a successful `build_timing` code-generation pass.
+///
+/// Returned by [`BuildTimingBuilder::build`]. Callers generally don't need to
+/// inspect this directly; the generated constants are consumed through the
+/// [`crate::build_timing!`] macro instead.
+#[derive(Debug, Clone)]
+pub struct BuildTiming {
+    consts: Vec<(String, ConstVal)>,
+}
+
+impl BuildTiming {
+    /// The resolved `(name, value)` pairs written into the generated `build_timing.rs`.
+    pub fn get_consts(&self) -> &[(String, ConstVal)] {
+        &self.consts
+    }
+
+    pub(crate) fn build_inner(builder: &mut BuildTimingBuilder) -> BtResult<Self> {
+        let out_path = builder.get_out_path()?.clone();
+
+        let mut consts = Vec::new();
+        let mut extra_paths = Vec::new();
+
+        for c in builder.get_allow_const() {
+            extra_paths.extend(c.rerun_if_changed());
+            consts.push((c.to_string(), c.build_val()));
+        }
+        for hook in &builder.hook_consts {
+            extra_paths.extend(hook.rerun_if_changed());
+            consts.push((hook.to_string(), hook.build_val()));
+        }
+
+        resolve_formats(&mut consts)?;
+
+        builder
+            .get_build_pattern()
+            .rerun_if(builder.get_allow_const().iter(), &out_path);
+        extra_paths
+            .iter()
+            .for_each(|p| println!("cargo:rerun-if-changed={p}"));
+
+        let dest = format!("{out_path}/{DEFINE_BUILD_TIMING_RS}");
+        if !builder
+            .get_build_pattern()
+            .should_skip_regeneration(&dest, &extra_paths)
+        {
+            fs::write(&dest, render(&consts))?;
+        }
+
+        if let Some(manifest_path) = builder.get_manifest_path() {
+            let dest = format!("{out_path}/{manifest_path}");
+            fs::write(dest, render_manifest(&consts))?;
+        }
+
+        Ok(BuildTiming { consts })
+    }
+}
+
+fn render(consts: &[(String, ConstVal)]) -> String {
+    let mut out = String::new();
+    for (name, val) in consts {
+        for line in val.desc.lines() {
+            out.push_str("/// ");
+            out.push_str(line);
+            out.push('\n');
+        }
+        match val.t {
+            ConstType::Str | ConstType::Format => {
+                out.push_str(&format!("pub const {name}: &str = {:?};\n\n", val.v));
+            }
+            ConstType::Bool => out.push_str(&format!("pub const {name}: bool = {};\n\n", val.v)),
+            ConstType::Slice => {
+                out.push_str(&format!(
+                    "pub const {name}: &[u8] = &{:?};\n\n",
+                    val.v.as_bytes()
+                ));
+            }
+            ConstType::Usize => out.push_str(&format!("pub const {name}: usize = {};\n\n", val.v)),
+        }
+    }
+    out
+}
+
+/// Renders every resolved constant as a stable JSON array of
+/// `{"name", "desc", "value", "type"}` objects, for [`emit_manifest`](crate::BuildTimingBuilder::emit_manifest).
+fn render_manifest(consts: &[(String, ConstVal)]) -> String {
+    let mut out = String::from("[\n");
+    for (i, (name, val)) in consts.iter().enumerate() {
+        if i > 0 {
+            out.push_str(",\n");
+        }
+        out.push_str(&format!(
+            "  {{\"name\": {}, \"desc\": {}, \"value\": {}, \"type\": {}}}",
+            json_escape(name),
+            json_escape(&val.desc),
+            json_escape(&val.v),
+            json_escape(&val.t.to_string()),
+        ));
+    }
+    out.push_str("\n]\n");
+    out
+}
+
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Resolves every `ConstType::Format` constant's `{NAME}` placeholders against
+/// the other constants in `consts`, in place. An unknown `{NAME}` is left
+/// untouched, and `{{`/`}}` escape a literal brace. Cyclic references (`A`
+/// references `B` references `A`) are rejected with a [`BuildTimingError`]
+/// instead of recursing forever.
+fn resolve_formats(consts: &mut [(String, ConstVal)]) -> BtResult<()> {
+    let raw: HashMap<String, (String, bool)> = consts
+        .iter()
+        .map(|(name, val)| {
+            (
+                name.clone(),
+                (val.v.clone(), matches!(val.t, ConstType::Format)),
+            )
+        })
+        .collect();
+
+    let mut resolved = HashMap::new();
+    let mut in_progress = HashSet::new();
+    for name in raw.keys() {
+        resolve_one(name, &raw, &mut resolved, &mut in_progress)?;
+    }
+
+    for (name, val) in consts.iter_mut() {
+        if matches!(val.t, ConstType::Format) {
+            val.v = resolved.get(name).cloned().unwrap_or_default();
+        }
+    }
+    Ok(())
+}
+
+fn resolve_one(
+    name: &str,
+    raw: &HashMap<String, (String, bool)>,
+    resolved: &mut HashMap<String, String>,
+    in_progress: &mut HashSet<String>,
+) -> BtResult<String> {
+    if let Some(v) = resolved.get(name) {
+        return Ok(v.clone());
+    }
+    let Some((raw_v, is_format)) = raw.get(name) else {
+        return Ok(String::new());
+    };
+    if !is_format {
+        resolved.insert(name.to_string(), raw_v.clone());
+        return Ok(raw_v.clone());
+    }
+    if !in_progress.insert(name.to_string()) {
+        return Err(BuildTimingError::from(format!(
+            "cyclic `ConstType::Format` reference detected at `{name}`"
+        )));
+    }
+    let out = interpolate(raw_v, raw, resolved, in_progress)?;
+    in_progress.remove(name);
+    resolved.insert(name.to_string(), out.clone());
+    Ok(out)
+}
+
+fn interpolate(
+    template: &str,
+    raw: &HashMap<String, (String, bool)>,
+    resolved: &mut HashMap<String, String>,
+    in_progress: &mut HashSet<String>,
+) -> BtResult<String> {
+    let mut out = String::new();
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                out.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                out.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                for c2 in chars.by_ref() {
+                    if c2 == '}' {
+                        break;
+                    }
+                    name.push(c2);
+                }
+                if raw.contains_key(&name) {
+                    out.push_str(&resolve_one(&name, raw, resolved, in_progress)?);
+                } else {
+                    out.push('{');
+                    out.push_str(&name);
+                    out.push('}');
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format_const(v: &str) -> ConstVal {
+        ConstVal {
+            desc: String::new(),
+            v: v.to_string(),
+            t: ConstType::Format,
+        }
+    }
+
+    fn str_const(v: &str) -> ConstVal {
+        ConstVal {
+            desc: String::new(),
+            v: v.to_string(),
+            t: ConstType::Str,
+        }
+    }
+
+    #[test]
+    fn resolve_formats_interpolates_other_consts() {
+        let mut consts = vec![
+            ("NAME".to_string(), str_const("world")),
+            ("GREETING".to_string(), format_const("hello {NAME}")),
+        ];
+        resolve_formats(&mut consts).unwrap();
+        assert_eq!(consts[1].1.v, "hello world");
+    }
+
+    #[test]
+    fn resolve_formats_leaves_unknown_placeholder_untouched() {
+        let mut consts = vec![("GREETING".to_string(), format_const("hello {NOBODY}"))];
+        resolve_formats(&mut consts).unwrap();
+        assert_eq!(consts[0].1.v, "hello {NOBODY}");
+    }
+
+    #[test]
+    fn resolve_formats_escapes_doubled_braces() {
+        let mut consts = vec![("LITERAL".to_string(), format_const("{{not a ref}}"))];
+        resolve_formats(&mut consts).unwrap();
+        assert_eq!(consts[0].1.v, "{not a ref}");
+    }
+
+    #[test]
+    fn resolve_formats_chains_transitive_references() {
+        let mut consts = vec![
+            ("A".to_string(), str_const("a")),
+            ("B".to_string(), format_const("{A}b")),
+            ("C".to_string(), format_const("{B}c")),
+        ];
+        resolve_formats(&mut consts).unwrap();
+        assert_eq!(consts[2].1.v, "abc");
+    }
+
+    #[test]
+    fn resolve_formats_rejects_cycles() {
+        let mut consts = vec![
+            ("A".to_string(), format_const("{B}")),
+            ("B".to_string(), format_const("{A}")),
+        ];
+        assert!(resolve_formats(&mut consts).is_err());
+    }
+
+    #[test]
+    fn json_escape_quotes_plain_strings() {
+        assert_eq!(json_escape("hello"), "\"hello\"");
+    }
+
+    #[test]
+    fn json_escape_escapes_special_characters() {
+        assert_eq!(
+            json_escape("a\"b\\c\nd\re\tf"),
+            "\"a\\\"b\\\\c\\nd\\re\\tf\""
+        );
+    }
+
+    #[test]
+    fn json_escape_escapes_control_characters() {
+        assert_eq!(json_escape("\u{1}"), "\"\\u0001\"");
+    }
+
+    #[test]
+    fn render_manifest_produces_json_array_of_consts() {
+        let consts = vec![
+            ("NAME".to_string(), str_const("world")),
+            ("COUNT".to_string(), ConstVal::new_bool("desc")),
+        ];
+        let manifest = render_manifest(&consts);
+        assert!(manifest.starts_with("[\n"));
+        assert!(manifest.ends_with("]\n"));
+        assert!(manifest.contains(r#""name": "NAME""#));
+        assert!(manifest.contains(r#""value": "world""#));
+        assert!(manifest.contains(r#""name": "COUNT""#));
+        assert!(manifest.contains(",\n"));
+    }
+
+    #[test]
+    fn render_manifest_of_empty_consts_is_empty_array() {
+        assert_eq!(render_manifest(&[]), "[\n\n]\n");
+    }
+}
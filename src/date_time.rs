@@ -6,6 +6,16 @@ use time::UtcOffset;
 use time::format_description::well_known::Rfc2822;
 
 pub(crate) const DEFINE_SOURCE_DATE_EPOCH: &str = "SOURCE_DATE_EPOCH";
+
+/// Parses `SOURCE_DATE_EPOCH` as a non-negative Unix timestamp, or `None` if it
+/// is unset or not a valid reproducible-build epoch. Shared with other build
+/// constants that need to suppress non-reproducible output under the same rule
+/// [`DateTime::now`] uses.
+pub(crate) fn parsed_source_date_epoch() -> Option<i64> {
+    let raw = std::env::var(DEFINE_SOURCE_DATE_EPOCH).ok()?;
+    let secs: i64 = raw.parse().ok()?;
+    (secs >= 0).then_some(secs)
+}
 pub enum DateTime {
     Local(OffsetDateTime),
     Utc(OffsetDateTime),
@@ -33,7 +43,19 @@ impl DateTime {
     }
 
     pub fn now() -> Self {
-        Self::local_now().unwrap_or_else(|_| DateTime::Utc(OffsetDateTime::now_utc()))
+        Self::from_source_date_epoch().unwrap_or_else(|| {
+            Self::local_now().unwrap_or_else(|_| DateTime::Utc(OffsetDateTime::now_utc()))
+        })
+    }
+
+    /// Reads the `SOURCE_DATE_EPOCH` environment variable and, if it holds a valid
+    /// non-negative Unix timestamp, returns a `DateTime::Utc` pinned to that instant.
+    /// See <https://reproducible-builds.org/docs/source-date-epoch/>.
+    fn from_source_date_epoch() -> Option<Self> {
+        let secs = parsed_source_date_epoch()?;
+        OffsetDateTime::from_unix_timestamp(secs)
+            .ok()
+            .map(DateTime::Utc)
     }
 
     pub fn to_rfc2822(&self) -> String {
@@ -1,6 +1,12 @@
 use crate::build::{ConstType, ConstVal};
 use lazy_static::lazy_static;
-use std::{collections::BTreeMap, env as std_env, fmt::Debug};
+use std::{
+    collections::BTreeMap,
+    env as std_env,
+    fmt::Debug,
+    path::{Path, PathBuf},
+    process::Command,
+};
 
 lazy_static! {
     pub(crate) static ref STD_ENV_MAP: BTreeMap<String, String> = {
@@ -23,8 +29,78 @@ where `os` is the operating system name as returned by [`std::env::consts::OS`],
 and `arch` is the computer architecture as returned by [`std::env::consts::ARCH`]."#;
 pub const BUILD_OS: BuildTimingConst = BuildTimingConst("BUILD_OS");
 
+const GIT_SHA_DOC: &str = r#"
+The full hash of the commit that `HEAD` points to."#;
+pub const GIT_SHA: BuildTimingConst = BuildTimingConst("GIT_SHA");
+
+const GIT_SHA_SHORT_DOC: &str = r#"
+The abbreviated hash of the commit that `HEAD` points to."#;
+pub const GIT_SHA_SHORT: BuildTimingConst = BuildTimingConst("GIT_SHA_SHORT");
+
+const GIT_BRANCH_DOC: &str = r#"
+The name of the branch `HEAD` is on, or empty when `HEAD` is detached."#;
+pub const GIT_BRANCH: BuildTimingConst = BuildTimingConst("GIT_BRANCH");
+
+const GIT_DIRTY_DOC: &str = r#"
+Whether the working tree had uncommitted changes at build time."#;
+pub const GIT_DIRTY: BuildTimingConst = BuildTimingConst("GIT_DIRTY");
+
+const GIT_COMMIT_DATE_DOC: &str = r#"
+The commit date (RFC 3339) of the commit that `HEAD` points to."#;
+pub const GIT_COMMIT_DATE: BuildTimingConst = BuildTimingConst("GIT_COMMIT_DATE");
+
+const RUST_CHANNEL_DOC: &str = r#"
+The release channel of the `rustc` used for the build: `stable`, `beta` or `nightly`."#;
+pub const RUST_CHANNEL: BuildTimingConst = BuildTimingConst("RUST_CHANNEL");
+
+const RUST_VERSION_DOC: &str = r#"
+The output of `rustc -vV`'s `release` line, e.g. `1.79.0-nightly`."#;
+pub const RUST_VERSION: BuildTimingConst = BuildTimingConst("RUST_VERSION");
+
+const CARGO_PROFILE_DOC: &str = r#"
+The build profile Cargo used for this build, as set in the `PROFILE` environment variable (`debug` or `release`)."#;
+pub const CARGO_PROFILE: BuildTimingConst = BuildTimingConst("CARGO_PROFILE");
+
+const CARGO_TARGET_DOC: &str = r#"
+The target triple Cargo is building for, as set in the `TARGET` environment variable."#;
+pub const CARGO_TARGET: BuildTimingConst = BuildTimingConst("CARGO_TARGET");
+
+#[cfg(feature = "sysinfo")]
+const BUILD_HOSTNAME_DOC: &str = r#"
+The hostname of the machine the build ran on. Gated behind the `sysinfo` feature
+because, unlike the other built-in constants, it is inherently non-reproducible."#;
+#[cfg(feature = "sysinfo")]
+pub const BUILD_HOSTNAME: BuildTimingConst = BuildTimingConst("BUILD_HOSTNAME");
+
+#[cfg(feature = "sysinfo")]
+const BUILD_USER_DOC: &str = r#"
+The user account the build ran under. Gated behind the `sysinfo` feature."#;
+#[cfg(feature = "sysinfo")]
+pub const BUILD_USER: BuildTimingConst = BuildTimingConst("BUILD_USER");
+
+#[cfg(feature = "sysinfo")]
+const BUILD_CPU_BRAND_DOC: &str = r#"
+The brand string of the build host's CPU, e.g. `AMD Ryzen 9 7950X`. Gated behind the `sysinfo` feature.
+Currently only populated on Linux (read from `/proc/cpuinfo`); empty elsewhere."#;
+#[cfg(feature = "sysinfo")]
+pub const BUILD_CPU_BRAND: BuildTimingConst = BuildTimingConst("BUILD_CPU_BRAND");
+
+#[cfg(feature = "sysinfo")]
+const BUILD_MEMORY_TOTAL_DOC: &str = r#"
+Total physical memory of the build host, in bytes. Gated behind the `sysinfo` feature.
+Currently only populated on Linux (read from `/proc/meminfo`); zero elsewhere."#;
+#[cfg(feature = "sysinfo")]
+pub const BUILD_MEMORY_TOTAL: BuildTimingConst = BuildTimingConst("BUILD_MEMORY_TOTAL");
+
 pub trait BuildConstVal: ToString + Debug {
     fn build_val(&self) -> ConstVal;
+
+    /// Extra filesystem paths that should trigger a rebuild when they change,
+    /// beyond the constant's own `cargo:rerun-if-env-changed` registration.
+    /// Defaults to none.
+    fn rerun_if_changed(&self) -> Vec<String> {
+        Vec::new()
+    }
 }
 
 impl BuildConstVal for BuildTimingConst {
@@ -35,9 +111,232 @@ impl BuildConstVal for BuildTimingConst {
                 v: format!("{}-{}", std::env::consts::OS, std::env::consts::ARCH),
                 t: ConstType::Str,
             },
+            &GIT_SHA => ConstVal {
+                desc: GIT_SHA_DOC.to_string(),
+                v: git_root()
+                    .and_then(|root| git_output(&root, &["rev-parse", "HEAD"]))
+                    .unwrap_or_default(),
+                t: ConstType::Str,
+            },
+            &GIT_SHA_SHORT => ConstVal {
+                desc: GIT_SHA_SHORT_DOC.to_string(),
+                v: git_root()
+                    .and_then(|root| git_output(&root, &["rev-parse", "--short", "HEAD"]))
+                    .unwrap_or_default(),
+                t: ConstType::Str,
+            },
+            &GIT_BRANCH => ConstVal {
+                desc: GIT_BRANCH_DOC.to_string(),
+                v: git_root()
+                    .and_then(|root| git_output(&root, &["rev-parse", "--abbrev-ref", "HEAD"]))
+                    .filter(|branch| branch != "HEAD")
+                    .unwrap_or_default(),
+                t: ConstType::Str,
+            },
+            &GIT_DIRTY => ConstVal {
+                desc: GIT_DIRTY_DOC.to_string(),
+                v: git_root().map(|root| git_is_dirty(&root)).unwrap_or(false).to_string(),
+                t: ConstType::Bool,
+            },
+            &GIT_COMMIT_DATE => ConstVal {
+                desc: GIT_COMMIT_DATE_DOC.to_string(),
+                v: git_root()
+                    .and_then(|root| git_output(&root, &["log", "-1", "--format=%cI"]))
+                    .unwrap_or_default(),
+                t: ConstType::Str,
+            },
+            &RUST_CHANNEL => ConstVal {
+                desc: RUST_CHANNEL_DOC.to_string(),
+                v: rustc_version_verbose()
+                    .map(|(_, channel)| channel)
+                    .unwrap_or_default(),
+                t: ConstType::Str,
+            },
+            &RUST_VERSION => ConstVal {
+                desc: RUST_VERSION_DOC.to_string(),
+                v: rustc_version_verbose()
+                    .map(|(release, _)| release)
+                    .unwrap_or_default(),
+                t: ConstType::Str,
+            },
+            &CARGO_PROFILE => ConstVal {
+                desc: CARGO_PROFILE_DOC.to_string(),
+                v: std_env::var("PROFILE").unwrap_or_default(),
+                t: ConstType::Str,
+            },
+            &CARGO_TARGET => ConstVal {
+                desc: CARGO_TARGET_DOC.to_string(),
+                v: std_env::var("TARGET").unwrap_or_default(),
+                t: ConstType::Str,
+            },
+            #[cfg(feature = "sysinfo")]
+            &BUILD_HOSTNAME => ConstVal {
+                desc: BUILD_HOSTNAME_DOC.to_string(),
+                v: if is_reproducible_build() {
+                    String::new()
+                } else {
+                    hostname().unwrap_or_default()
+                },
+                t: ConstType::Str,
+            },
+            #[cfg(feature = "sysinfo")]
+            &BUILD_USER => ConstVal {
+                desc: BUILD_USER_DOC.to_string(),
+                v: if is_reproducible_build() {
+                    String::new()
+                } else {
+                    std_env::var("USER")
+                        .or_else(|_| std_env::var("USERNAME"))
+                        .unwrap_or_default()
+                },
+                t: ConstType::Str,
+            },
+            #[cfg(feature = "sysinfo")]
+            &BUILD_CPU_BRAND => ConstVal {
+                desc: BUILD_CPU_BRAND_DOC.to_string(),
+                v: if is_reproducible_build() {
+                    String::new()
+                } else {
+                    cpu_brand().unwrap_or_default()
+                },
+                t: ConstType::Str,
+            },
+            #[cfg(feature = "sysinfo")]
+            &BUILD_MEMORY_TOTAL => ConstVal {
+                desc: BUILD_MEMORY_TOTAL_DOC.to_string(),
+                v: if is_reproducible_build() {
+                    0.to_string()
+                } else {
+                    memory_total_bytes().unwrap_or(0).to_string()
+                },
+                t: ConstType::Usize,
+            },
             _ => panic!("Unknown build constant: {}", self.to_string()),
         }
     }
+
+    fn rerun_if_changed(&self) -> Vec<String> {
+        match self {
+            &GIT_SHA | &GIT_SHA_SHORT | &GIT_BRANCH | &GIT_DIRTY | &GIT_COMMIT_DATE => {
+                git_root().map(|root| git_rerun_paths(&root)).unwrap_or_default()
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Walks up from `CARGO_MANIFEST_DIR` looking for a `.git` directory.
+/// Returns `None` when no repository is found, so non-git builds still succeed.
+fn git_root() -> Option<PathBuf> {
+    let mut dir = PathBuf::from(std_env::var("CARGO_MANIFEST_DIR").ok()?);
+    loop {
+        if dir.join(".git").exists() {
+            return Some(dir);
+        }
+        if !dir.pop() {
+            return None;
+        }
+    }
+}
+
+fn git_output(root: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git").arg("-C").arg(root).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout)
+        .ok()
+        .map(|s| s.trim().to_string())
+}
+
+fn git_is_dirty(root: &Path) -> bool {
+    git_output(root, &["status", "--porcelain"])
+        .map(|s| !s.is_empty())
+        .unwrap_or(false)
+}
+
+/// Whether reproducible-build output has been requested via `SOURCE_DATE_EPOCH`.
+/// Host facts like hostname or CPU brand are inherently non-reproducible, so the
+/// `sysinfo` constants suppress themselves (emit empty/zero) in that case.
+#[cfg(feature = "sysinfo")]
+fn is_reproducible_build() -> bool {
+    crate::date_time::parsed_source_date_epoch().is_some()
+}
+
+#[cfg(feature = "sysinfo")]
+fn hostname() -> Option<String> {
+    if let Ok(name) = std_env::var("HOSTNAME") {
+        return Some(name);
+    }
+    let output = Command::new("hostname").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout)
+        .ok()
+        .map(|s| s.trim().to_string())
+}
+
+#[cfg(feature = "sysinfo")]
+fn cpu_brand() -> Option<String> {
+    let cpuinfo = std::fs::read_to_string("/proc/cpuinfo").ok()?;
+    cpuinfo
+        .lines()
+        .find_map(|line| line.strip_prefix("model name"))
+        .and_then(|line| line.split(':').nth(1))
+        .map(|v| v.trim().to_string())
+}
+
+#[cfg(feature = "sysinfo")]
+fn memory_total_bytes() -> Option<usize> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+    let kib: usize = meminfo
+        .lines()
+        .find_map(|line| line.strip_prefix("MemTotal:"))
+        .and_then(|line| line.trim().strip_suffix("kB"))
+        .and_then(|n| n.trim().parse().ok())?;
+    Some(kib * 1024)
+}
+
+/// Runs `rustc -vV` and returns `(release, channel)`, e.g.
+/// `("1.79.0-nightly", "nightly")`. The channel is classified from the
+/// `-nightly`/`-beta` suffix on the `release:` line, defaulting to `stable`.
+fn rustc_version_verbose() -> Option<(String, String)> {
+    let rustc = std_env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let output = Command::new(rustc).arg("-vV").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    let release = stdout
+        .lines()
+        .find_map(|line| line.strip_prefix("release: "))?
+        .to_string();
+    let channel = if release.contains("-nightly") {
+        "nightly"
+    } else if release.contains("-beta") {
+        "beta"
+    } else {
+        "stable"
+    }
+    .to_string();
+    Some((release, channel))
+}
+
+/// Paths that should be watched for changes to pick up new commits: `HEAD`
+/// itself, the packed refs file, and the ref `HEAD` currently resolves to.
+fn git_rerun_paths(root: &Path) -> Vec<String> {
+    let git_dir = root.join(".git");
+    let mut paths = vec![
+        git_dir.join("HEAD").display().to_string(),
+        git_dir.join("packed-refs").display().to_string(),
+    ];
+    if let Ok(head) = std::fs::read_to_string(git_dir.join("HEAD")) {
+        if let Some(ref_path) = head.trim().strip_prefix("ref: ") {
+            paths.push(git_dir.join(ref_path).display().to_string());
+        }
+    }
+    paths
 }
 
 impl Ord for BuildTimingConst {
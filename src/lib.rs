@@ -10,7 +10,12 @@ pub const CARGO_CLIPPY_ALLOW_ALL: &str =
 #[cfg(feature = "build")]
 mod pub_export {
     pub use crate::build::{BuildPattern, BuildTimingBuilder, ConstVal, ConstType};
-    pub use crate::env::{BuildConstVal, BuildTimingConst};
+    pub use crate::env::{
+        BuildConstVal, BuildTimingConst, BUILD_OS, CARGO_PROFILE, CARGO_TARGET, GIT_BRANCH,
+        GIT_COMMIT_DATE, GIT_DIRTY, GIT_SHA, GIT_SHA_SHORT, RUST_CHANNEL, RUST_VERSION,
+    };
+    #[cfg(feature = "sysinfo")]
+    pub use crate::env::{BUILD_CPU_BRAND, BUILD_HOSTNAME, BUILD_MEMORY_TOTAL, BUILD_USER};
     pub use crate::date_time::DateTime;
     pub use crate::err::{BtResult, BuildTimingError};
     pub use crate::build_timing::BuildTiming;